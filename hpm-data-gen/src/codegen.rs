@@ -0,0 +1,398 @@
+use std::{collections::BTreeMap, fmt::Write as _, path::Path};
+
+/// One row of `foreach_peripheral!`: the peripheral's name, its kind (the
+/// lowercase driver family, e.g. `uart`), and its global interrupt name.
+struct PeripheralRow {
+    name: String,
+    kind: String,
+    interrupt: String,
+}
+
+/// One row of `foreach_interrupt!`: peripheral, signal, and IRQ name.
+struct InterruptRow {
+    peripheral: String,
+    signal: String,
+    interrupt: String,
+}
+
+/// One row of `foreach_dma_channel!`: peripheral, signal, dmamux instance,
+/// request number, and the (controller, request number) pairs for every DMA
+/// engine able to service it — so a build.rs can pick HDMA vs XDMA.
+struct DmaChannelRow {
+    peripheral: String,
+    signal: String,
+    dmamux: String,
+    request: u8,
+    controllers: Vec<(String, u8)>,
+}
+
+/// One row of `foreach_pin!`: peripheral, pin, signal, and alternate
+/// function number, mirroring the `Pin { pin, signal, af }` records the
+/// stm32-metapac crates expose for pin-to-peripheral type checking.
+struct PinRow {
+    peripheral: String,
+    pin: String,
+    signal: String,
+    af: u8,
+}
+
+/// Lowers a fully-populated [`hpm_data_serde::Chip`] into `generated.rs`: a
+/// `pub const METADATA: Metadata` plus `foreach_peripheral!`,
+/// `foreach_interrupt!`, `foreach_dma_channel!`, and `foreach_pin!`
+/// macrotables, mirroring what embassy's stm32-metapac exposes to
+/// downstream HAL build scripts.
+pub fn write_chip_metadata(chip: &hpm_data_serde::Chip, out_path: &Path) -> anyhow::Result<()> {
+    write_metadata(&chip.name, &chip.cores, out_path)
+}
+
+/// Same as [`write_chip_metadata`], but scoped to a single core. Used when
+/// deduplicating cores across the chip matrix, so the emitted metadata only
+/// covers the one core that was hashed rather than every core of the chip.
+pub fn write_core_metadata(
+    chip_name: &str,
+    core: &hpm_data_serde::chip::core::Core,
+    out_path: &Path,
+) -> anyhow::Result<()> {
+    write_metadata(chip_name, std::slice::from_ref(core), out_path)
+}
+
+fn write_metadata(
+    chip_name: &str,
+    cores: &[hpm_data_serde::chip::core::Core],
+    out_path: &Path,
+) -> anyhow::Result<()> {
+    let (peripherals, interrupts, dma_channels, pins) = collect_rows(cores);
+
+    let mut out = String::new();
+    writeln!(out, "// Auto-generated by hpm-data-gen. Do not edit by hand.")?;
+    writeln!(out, "#![allow(dead_code)]")?;
+    writeln!(out)?;
+
+    write_types(&mut out)?;
+    write_metadata_const(&mut out, &peripherals, &interrupts, &dma_channels, &pins)?;
+    write_foreach_peripheral(&mut out, &peripherals)?;
+    write_foreach_interrupt(&mut out, &interrupts)?;
+    write_foreach_dma_channel(&mut out, &dma_channels)?;
+    write_foreach_pin(&mut out, &pins)?;
+
+    std::fs::write(out_path, out).map_err(|e| {
+        anyhow::anyhow!("Failed to write generated metadata {:?}: {}", out_path, e)
+    })?;
+
+    println!(
+        "    Wrote metadata for {} ({} peripherals, {} interrupts, {} dma channels, {} pins) to {:?}",
+        chip_name,
+        peripherals.len(),
+        interrupts.len(),
+        dma_channels.len(),
+        pins.len(),
+        out_path.file_name().unwrap()
+    );
+
+    Ok(())
+}
+
+fn collect_rows(
+    cores: &[hpm_data_serde::chip::core::Core],
+) -> (
+    Vec<PeripheralRow>,
+    Vec<InterruptRow>,
+    Vec<DmaChannelRow>,
+    Vec<PinRow>,
+) {
+    let mut peripherals = Vec::new();
+    let mut interrupts = Vec::new();
+    let mut dma_channels = Vec::new();
+    let mut pins = Vec::new();
+
+    for core in cores {
+        for periph in &core.peripherals {
+            let global_interrupt = periph
+                .interrupts
+                .as_ref()
+                .and_then(|ints| ints.iter().find(|i| i.signal == "GLOBAL"))
+                .map(|i| i.interrupt.clone())
+                .unwrap_or_default();
+
+            peripherals.push(PeripheralRow {
+                name: periph.name.clone(),
+                kind: peripheral_kind(&periph.name),
+                interrupt: global_interrupt,
+            });
+
+            for interrupt in periph.interrupts.iter().flatten() {
+                interrupts.push(InterruptRow {
+                    peripheral: periph.name.clone(),
+                    signal: interrupt.signal.clone(),
+                    interrupt: interrupt.interrupt.clone(),
+                });
+            }
+
+            for dma_channel in &periph.dma_channels {
+                dma_channels.push(DmaChannelRow {
+                    peripheral: periph.name.clone(),
+                    signal: dma_channel.signal.clone(),
+                    dmamux: dma_channel.dmamux.clone().unwrap_or_default(),
+                    request: dma_channel.request,
+                    controllers: dma_channel.controllers.clone(),
+                });
+            }
+
+            for pin in periph.pins.iter().flatten() {
+                pins.push(PinRow {
+                    peripheral: periph.name.clone(),
+                    pin: pin.pin.clone(),
+                    signal: pin.signal.clone(),
+                    af: pin.af,
+                });
+            }
+        }
+    }
+
+    pins.sort_by(|a, b| (&a.peripheral, &a.pin, &a.signal).cmp(&(&b.peripheral, &b.pin, &b.signal)));
+
+    peripherals.sort_by(|a, b| a.name.cmp(&b.name));
+    peripherals.dedup_by(|a, b| a.name == b.name);
+
+    (peripherals, interrupts, dma_channels, pins)
+}
+
+/// Derives the lowercase driver family from a peripheral name, e.g.
+/// `UART0` -> `uart`, `GPTMR3` -> `gptmr`.
+fn peripheral_kind(name: &str) -> String {
+    name.trim_end_matches(|c: char| c.is_ascii_digit())
+        .to_lowercase()
+}
+
+fn write_types(out: &mut String) -> anyhow::Result<()> {
+    writeln!(out, "pub struct PeripheralData {{")?;
+    writeln!(out, "    pub name: &'static str,")?;
+    writeln!(out, "    pub kind: &'static str,")?;
+    writeln!(out, "    pub interrupt: &'static str,")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(out, "pub struct InterruptData {{")?;
+    writeln!(out, "    pub peripheral: &'static str,")?;
+    writeln!(out, "    pub signal: &'static str,")?;
+    writeln!(out, "    pub interrupt: &'static str,")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(out, "pub struct DmaChannelData {{")?;
+    writeln!(out, "    pub peripheral: &'static str,")?;
+    writeln!(out, "    pub signal: &'static str,")?;
+    writeln!(out, "    pub dmamux: &'static str,")?;
+    writeln!(out, "    pub request: u8,")?;
+    writeln!(out, "    pub controllers: &'static [(&'static str, u8)],")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(out, "pub struct PinData {{")?;
+    writeln!(out, "    pub peripheral: &'static str,")?;
+    writeln!(out, "    pub pin: &'static str,")?;
+    writeln!(out, "    pub signal: &'static str,")?;
+    writeln!(out, "    pub af: u8,")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(out, "pub struct Metadata {{")?;
+    writeln!(out, "    pub peripherals: &'static [PeripheralData],")?;
+    writeln!(out, "    pub interrupts: &'static [InterruptData],")?;
+    writeln!(out, "    pub dma_channels: &'static [DmaChannelData],")?;
+    writeln!(out, "    pub pins: &'static [PinData],")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn write_metadata_const(
+    out: &mut String,
+    peripherals: &[PeripheralRow],
+    interrupts: &[InterruptRow],
+    dma_channels: &[DmaChannelRow],
+    pins: &[PinRow],
+) -> anyhow::Result<()> {
+    writeln!(out, "pub const METADATA: Metadata = Metadata {{")?;
+
+    writeln!(out, "    peripherals: &[")?;
+    for p in peripherals {
+        writeln!(
+            out,
+            "        PeripheralData {{ name: {:?}, kind: {:?}, interrupt: {:?} }},",
+            p.name, p.kind, p.interrupt
+        )?;
+    }
+    writeln!(out, "    ],")?;
+
+    writeln!(out, "    interrupts: &[")?;
+    for i in interrupts {
+        writeln!(
+            out,
+            "        InterruptData {{ peripheral: {:?}, signal: {:?}, interrupt: {:?} }},",
+            i.peripheral, i.signal, i.interrupt
+        )?;
+    }
+    writeln!(out, "    ],")?;
+
+    writeln!(out, "    dma_channels: &[")?;
+    for d in dma_channels {
+        let controllers = d
+            .controllers
+            .iter()
+            .map(|(name, request)| format!("({:?}, {})", name, request))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            out,
+            "        DmaChannelData {{ peripheral: {:?}, signal: {:?}, dmamux: {:?}, request: {}, controllers: &[{}] }},",
+            d.peripheral, d.signal, d.dmamux, d.request, controllers
+        )?;
+    }
+    writeln!(out, "    ],")?;
+
+    writeln!(out, "    pins: &[")?;
+    for p in pins {
+        writeln!(
+            out,
+            "        PinData {{ peripheral: {:?}, pin: {:?}, signal: {:?}, af: {} }},",
+            p.peripheral, p.pin, p.signal, p.af
+        )?;
+    }
+    writeln!(out, "    ],")?;
+
+    writeln!(out, "}};")?;
+    writeln!(out)?;
+    Ok(())
+}
+
+/// Groups rows by their first macro argument, so each `foreach_*!` macro
+/// lists peripherals in sorted order, each peripheral's rows kept in their
+/// original order.
+fn group_by_peripheral<'a, T>(
+    rows: &'a [T],
+    peripheral: impl Fn(&'a T) -> &'a str,
+) -> BTreeMap<&'a str, Vec<&'a T>> {
+    let mut grouped: BTreeMap<&str, Vec<&T>> = BTreeMap::new();
+    for row in rows {
+        grouped.entry(peripheral(row)).or_default().push(row);
+    }
+    grouped
+}
+
+/// Bare identifier to stand in for a row field that has no value (e.g. a
+/// peripheral with no `GLOBAL` interrupt). Rows are emitted as unquoted
+/// tokens so callers can pattern-match on them, so an empty string would
+/// otherwise produce a malformed, arity-mismatched row.
+fn macro_token(value: &str) -> &str {
+    if value.is_empty() {
+        "NONE"
+    } else {
+        value
+    }
+}
+
+/// Each `foreach_*!` macro is self-contained: it takes the caller's
+/// `$pat => $body;` arms and splices them verbatim into a `macro_rules!`
+/// defined on the spot, then feeds every known row through it. A trailing
+/// wildcard arm swallows rows the caller's patterns don't match, so the
+/// caller only needs to cover the rows it cares about. This needs no
+/// separate `_foreach_*_arm!` helper macro, since the caller's patterns
+/// become literal matcher tokens of the generated `macro_rules!` itself.
+fn write_foreach_peripheral(out: &mut String, peripherals: &[PeripheralRow]) -> anyhow::Result<()> {
+    writeln!(out, "#[macro_export]")?;
+    writeln!(out, "macro_rules! foreach_peripheral {{")?;
+    writeln!(out, "    ($($pat:tt => $body:tt;)*) => {{")?;
+    writeln!(out, "        macro_rules! __hpm_peripheral_row {{")?;
+    writeln!(out, "            $(($pat) => $body;)*")?;
+    writeln!(out, "            ($t:tt) => {{}};")?;
+    writeln!(out, "        }}")?;
+    for p in peripherals {
+        writeln!(
+            out,
+            "        __hpm_peripheral_row!(({}, {}, {}));",
+            p.name,
+            p.kind,
+            macro_token(&p.interrupt)
+        )?;
+    }
+    writeln!(out, "    }};")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn write_foreach_interrupt(out: &mut String, interrupts: &[InterruptRow]) -> anyhow::Result<()> {
+    writeln!(out, "#[macro_export]")?;
+    writeln!(out, "macro_rules! foreach_interrupt {{")?;
+    writeln!(out, "    ($($pat:tt => $body:tt;)*) => {{")?;
+    writeln!(out, "        macro_rules! __hpm_interrupt_row {{")?;
+    writeln!(out, "            $(($pat) => $body;)*")?;
+    writeln!(out, "            ($t:tt) => {{}};")?;
+    writeln!(out, "        }}")?;
+    for (peripheral, rows) in group_by_peripheral(interrupts, |r| r.peripheral.as_str()) {
+        for i in rows {
+            writeln!(
+                out,
+                "        __hpm_interrupt_row!(({}, {}, {}));",
+                peripheral, i.signal, i.interrupt
+            )?;
+        }
+    }
+    writeln!(out, "    }};")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn write_foreach_pin(out: &mut String, pins: &[PinRow]) -> anyhow::Result<()> {
+    writeln!(out, "#[macro_export]")?;
+    writeln!(out, "macro_rules! foreach_pin {{")?;
+    writeln!(out, "    ($($pat:tt => $body:tt;)*) => {{")?;
+    writeln!(out, "        macro_rules! __hpm_pin_row {{")?;
+    writeln!(out, "            $(($pat) => $body;)*")?;
+    writeln!(out, "            ($t:tt) => {{}};")?;
+    writeln!(out, "        }}")?;
+    for (peripheral, rows) in group_by_peripheral(pins, |r| r.peripheral.as_str()) {
+        for p in rows {
+            writeln!(
+                out,
+                "        __hpm_pin_row!(({}, {}, {}, {}));",
+                peripheral, p.pin, p.signal, p.af
+            )?;
+        }
+    }
+    writeln!(out, "    }};")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    Ok(())
+}
+
+fn write_foreach_dma_channel(out: &mut String, dma_channels: &[DmaChannelRow]) -> anyhow::Result<()> {
+    writeln!(out, "#[macro_export]")?;
+    writeln!(out, "macro_rules! foreach_dma_channel {{")?;
+    writeln!(out, "    ($($pat:tt => $body:tt;)*) => {{")?;
+    writeln!(out, "        macro_rules! __hpm_dma_channel_row {{")?;
+    writeln!(out, "            $(($pat) => $body;)*")?;
+    writeln!(out, "            ($t:tt) => {{}};")?;
+    writeln!(out, "        }}")?;
+    for (peripheral, rows) in group_by_peripheral(dma_channels, |r| r.peripheral.as_str()) {
+        for d in rows {
+            let controllers = d
+                .controllers
+                .iter()
+                .map(|(name, _)| macro_token(name))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                out,
+                "        __hpm_dma_channel_row!(({}, {}, {}, {:#04x}, [{}]));",
+                peripheral,
+                d.signal,
+                macro_token(&d.dmamux),
+                d.request,
+                controllers
+            )?;
+        }
+    }
+    writeln!(out, "    }};")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    Ok(())
+}
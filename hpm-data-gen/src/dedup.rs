@@ -0,0 +1,117 @@
+use crate::codegen;
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+    path::Path,
+};
+
+/// Content-hashes a core's peripheral/interrupt/DMA/pin data after sorting it
+/// into a stable order, so two cores that are byte-identical except for
+/// header-parse ordering still hash the same.
+fn hash_core(core: &hpm_data_serde::chip::core::Core) -> u64 {
+    let mut peripherals = core.peripherals.clone();
+    peripherals.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut hasher = DefaultHasher::new();
+
+    for periph in &mut peripherals {
+        periph.name.hash(&mut hasher);
+
+        let mut interrupts = periph.interrupts.clone().unwrap_or_default();
+        interrupts.sort_by(|a, b| {
+            (&a.signal, &a.interrupt).cmp(&(&b.signal, &b.interrupt))
+        });
+        for interrupt in &interrupts {
+            interrupt.signal.hash(&mut hasher);
+            interrupt.interrupt.hash(&mut hasher);
+        }
+
+        let mut dma_channels = periph.dma_channels.clone();
+        dma_channels.sort_by(|a, b| (&a.signal, a.request).cmp(&(&b.signal, b.request)));
+        for dma_channel in &mut dma_channels {
+            dma_channel.signal.hash(&mut hasher);
+            dma_channel.dmamux.hash(&mut hasher);
+            dma_channel.request.hash(&mut hasher);
+
+            dma_channel.controllers.sort();
+            for (controller, request) in &dma_channel.controllers {
+                controller.hash(&mut hasher);
+                request.hash(&mut hasher);
+            }
+        }
+
+        let mut pins = periph.pins.clone().unwrap_or_default();
+        pins.sort_by(|a, b| (&a.pin, &a.signal, a.af).cmp(&(&b.pin, &b.signal, b.af)));
+        for pin in &pins {
+            pin.pin.hash(&mut hasher);
+            pin.signal.hash(&mut hasher);
+            pin.af.hash(&mut hasher);
+        }
+    }
+
+    let mut interrupts = core.interrupts.clone();
+    interrupts.sort_by(|a, b| a.name.cmp(&b.name));
+    for interrupt in &interrupts {
+        interrupt.name.hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+/// Deduplicates identical cores across the whole chip matrix before
+/// generating metadata: as the `HPM53`/`HPM62`/`HPM67` families each map
+/// many parts onto one representative header, most of their per-core output
+/// ends up byte-identical. Each *core* that hashes the same shares one
+/// canonical `generated.rs` body under `shared/`, and every chip gets one
+/// output file per core (`{chip}_core{index}.rs`) that just `include!`s the
+/// blob for its hash, mirroring the size-reduction pass embassy runs to keep
+/// stm32-metapac publishable.
+pub fn write_deduped_metadata(
+    chips: &[(String, hpm_data_serde::Chip)],
+    out_dir: &Path,
+) -> anyhow::Result<()> {
+    let shared_dir = out_dir.join("shared");
+    std::fs::create_dir_all(&shared_dir)
+        .map_err(|e| anyhow::anyhow!("Failed to create {:?}: {}", shared_dir, e))?;
+
+    let mut canonical: BTreeMap<u64, String> = BTreeMap::new();
+    let mut total_cores = 0usize;
+
+    for (chip_name, chip) in chips {
+        for (core_index, core) in chip.cores.iter().enumerate() {
+            total_cores += 1;
+            let hash = hash_core(core);
+            let shared_file = format!("{:016x}.rs", hash);
+
+            if let std::collections::btree_map::Entry::Vacant(entry) = canonical.entry(hash) {
+                let shared_path = shared_dir.join(&shared_file);
+                codegen::write_core_metadata(chip_name, core, &shared_path)?;
+                entry.insert(shared_file.clone());
+            }
+
+            let chip_out_path = out_dir.join(format!("{}_core{}.rs", chip_name, core_index));
+            std::fs::write(
+                &chip_out_path,
+                format!(
+                    "// Auto-generated by hpm-data-gen. Do not edit by hand.\ninclude!(\"shared/{}\");\n",
+                    canonical[&hash]
+                ),
+            )
+            .map_err(|e| anyhow::anyhow!("Failed to write {:?}: {}", chip_out_path, e))?;
+        }
+    }
+
+    let unique = canonical.len();
+    println!(
+        "    Deduplicated {} cores into {} unique metadata blob(s) ({:.0}% reduction)",
+        total_cores,
+        unique,
+        if total_cores > 0 {
+            100.0 * (1.0 - unique as f64 / total_cores as f64)
+        } else {
+            0.0
+        }
+    );
+
+    Ok(())
+}
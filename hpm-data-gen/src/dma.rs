@@ -37,27 +37,55 @@ fn parse_dmamux_from_header(header_path: &Path) -> anyhow::Result<HashMap<String
     Ok(dmamux)
 }
 
-/// Get dmamux header path for chip
-fn get_dmamux_header_path(chip_name: &str) -> Option<PathBuf> {
+/// A DMA engine present on a chip, and the dmamux instance name attached to
+/// the `DmaChannel`s it services.
+struct DmaController {
+    /// e.g. `"HDMA"`, `"XDMA"`.
+    name: &'static str,
+    /// dmamux instance name, e.g. `"DMAMUX"`, `"XDMAMUX"`.
+    dmamux: &'static str,
+    /// Request-table header basename for this engine.
+    header_file: &'static str,
+}
+
+/// DMA engines to probe for, in priority order: the first one whose header
+/// defines a given signal becomes that signal's primary (and its `request`
+/// number), with every other engine that also defines it recorded in
+/// `controllers`.
+const DMA_CONTROLLERS: &[DmaController] = &[
+    DmaController {
+        name: "HDMA",
+        dmamux: "DMAMUX",
+        header_file: "hpm_dmamux_src.h",
+    },
+    DmaController {
+        name: "XDMA",
+        dmamux: "XDMAMUX",
+        header_file: "hpm_xdmamux_src.h",
+    },
+];
+
+/// Get a DMA controller's request-table header path for chip
+fn get_dmamux_header_path(chip_name: &str, header_file: &str) -> Option<PathBuf> {
     let sdk_path = std::env::var("HPM_SDK_BASE")
         .map(PathBuf::from)
         .unwrap_or_else(|_| std::env::current_dir().unwrap().join("./hpm_sdk"));
 
-    let header_path = match chip_name {
-        n if n.starts_with("HPM5301") => sdk_path.join("soc/HPM5300/HPM5301/hpm_dmamux_src.h"),
-        n if n.starts_with("HPM53") => sdk_path.join("soc/HPM5300/HPM5361/hpm_dmamux_src.h"),
-        n if n.starts_with("HPM5E") => sdk_path.join("soc/HPM5E00/HPM5E31/hpm_dmamux_src.h"),
-        n if n.starts_with("HPM62") => sdk_path.join("soc/HPM6200/HPM6280/hpm_dmamux_src.h"),
-        n if n.starts_with("HPM63") => sdk_path.join("soc/HPM6300/HPM6360/hpm_dmamux_src.h"),
-        n if n.starts_with("HPM67") || n.starts_with("HPM64") => {
-            sdk_path.join("soc/HPM6700/HPM6750/hpm_dmamux_src.h")
-        }
-        n if n.starts_with("HPM68") => sdk_path.join("soc/HPM6800/HPM6880/hpm_dmamux_src.h"),
-        n if n.starts_with("HPM6E") => sdk_path.join("soc/HPM6E00/HPM6E80/hpm_dmamux_src.h"),
-        n if n.starts_with("HPM6P") => sdk_path.join("soc/HPM6P00/HPM6P81/hpm_dmamux_src.h"),
+    let soc_dir = match chip_name {
+        n if n.starts_with("HPM5301") => "soc/HPM5300/HPM5301",
+        n if n.starts_with("HPM53") => "soc/HPM5300/HPM5361",
+        n if n.starts_with("HPM5E") => "soc/HPM5E00/HPM5E31",
+        n if n.starts_with("HPM62") => "soc/HPM6200/HPM6280",
+        n if n.starts_with("HPM63") => "soc/HPM6300/HPM6360",
+        n if n.starts_with("HPM67") || n.starts_with("HPM64") => "soc/HPM6700/HPM6750",
+        n if n.starts_with("HPM68") => "soc/HPM6800/HPM6880",
+        n if n.starts_with("HPM6E") => "soc/HPM6E00/HPM6E80",
+        n if n.starts_with("HPM6P") => "soc/HPM6P00/HPM6P81",
         _ => return None,
     };
 
+    let header_path = sdk_path.join(soc_dir).join(header_file);
+
     if header_path.exists() {
         Some(header_path)
     } else {
@@ -86,43 +114,94 @@ pub fn handle_chip_dmamux_include<P: AsRef<Path>>(
     chip: &mut hpm_data_serde::Chip,
 ) -> anyhow::Result<()> {
     let meta_yaml_path = path.as_ref();
+    let _ = meta_yaml_path;
 
     for core in &mut chip.cores {
-        if let Some(_include_path) = core.include_dmamux.take() {
-            // Load DMAMUX directly from SDK header file (more accurate than YAML)
+        if core.include_dmamux.take().is_none() {
+            continue;
+        }
+
+        // Load every DMA controller's request table that exists for this
+        // chip, directly from the SDK headers (more accurate than YAML).
+        println!(
+            "    Loading DMAMUX from header file(s) for chip: {}",
+            chip.name
+        );
+
+        let mut by_controller = Vec::new();
+        for controller in DMA_CONTROLLERS {
+            if let Some(header_path) = get_dmamux_header_path(&chip.name, controller.header_file) {
+                by_controller.push((controller, parse_dmamux_from_header(&header_path)?));
+            }
+        }
+
+        if by_controller.is_empty() {
             println!(
-                "    Loading DMAMUX from header file for chip: {}",
+                "    ⚠️  No DMAMUX header found for chip: {}, skipping",
                 chip.name
             );
+            continue;
+        }
 
-            let dmamux = if let Some(header_path) = get_dmamux_header_path(&chip.name) {
-                parse_dmamux_from_header(&header_path)?
-            } else {
-                println!(
-                    "    ⚠️  No DMAMUX header found for chip: {}, skipping",
-                    chip.name
-                );
-                continue;
-            };
-
-            // Process the dmamux data (same logic for both YAML and header sources)
-            for (signal_name, request_no) in dmamux {
-                for periph in core.peripherals.iter_mut() {
-                    let signal_periph_prefix =
-                        signal_name.split('_').next().expect("empty signal_name");
-                    if periph.name == signal_periph_prefix {
-                        // println!("matches signal_name: {:#?}", signal_name);
-
-                        let signal = parse_signal(&signal_name, &periph.name);
-
-                        periph.dma_channels.push(
-                            hpm_data_serde::chip::core::peripheral::DmaChannel {
-                                signal: signal.clone(),
-                                dmamux: Some("DMAMUX".to_string()),
-                                request: request_no as u8,
-                            },
-                        );
+        // HPM SoCs have separate DMA engines with overlapping request-number
+        // spaces, so a signal serviced by more than one controller isn't
+        // guaranteed to use the same request number on each. Resolve, per
+        // signal name, the primary controller (the first one that defines
+        // it, whose request number becomes `request`) and the request
+        // number on every controller able to service it.
+        let mut resolved: HashMap<String, (&DmaController, usize, Vec<(String, usize)>)> =
+            HashMap::new();
+        for (controller, requests) in &by_controller {
+            for (signal_name, request_no) in requests {
+                resolved
+                    .entry(signal_name.clone())
+                    .and_modify(|(_, _, controllers)| {
+                        controllers.push((controller.name.to_string(), *request_no))
+                    })
+                    .or_insert_with(|| {
+                        (
+                            controller,
+                            *request_no,
+                            vec![(controller.name.to_string(), *request_no)],
+                        )
+                    });
+            }
+        }
+
+        for (signal_name, (primary, request_no, controllers)) in resolved {
+            for periph in core.peripherals.iter_mut() {
+                let signal_periph_prefix =
+                    signal_name.split('_').next().expect("empty signal_name");
+                if periph.name == signal_periph_prefix {
+                    // println!("matches signal_name: {:#?}", signal_name);
+
+                    let signal = parse_signal(&signal_name, &periph.name);
+
+                    for (controller_name, controller_request_no) in &controllers {
+                        if *controller_request_no != request_no {
+                            println!(
+                                "    ⚠️  {} routes {} to request {:#04x} on {} but {:#04x} on {}; recording both",
+                                chip.name,
+                                signal_name,
+                                request_no,
+                                primary.name,
+                                controller_request_no,
+                                controller_name
+                            );
+                        }
                     }
+
+                    periph.dma_channels.push(
+                        hpm_data_serde::chip::core::peripheral::DmaChannel {
+                            signal: signal.clone(),
+                            dmamux: Some(primary.dmamux.to_string()),
+                            request: request_no as u8,
+                            controllers: controllers
+                                .iter()
+                                .map(|(name, req)| (name.clone(), *req as u8))
+                                .collect(),
+                        },
+                    );
                 }
             }
         }
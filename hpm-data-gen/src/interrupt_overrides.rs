@@ -0,0 +1,97 @@
+use serde::Deserialize;
+use std::{collections::HashMap, path::PathBuf};
+
+/// Per-chip interrupt corrections, checked in under `interrupt_overrides/`.
+///
+/// Header `#define`s aren't always the final word: some dies share a
+/// representative header with other parts in their family (see
+/// `get_interrupts_header_path`'s `starts_with("HPM53")` fallback) and so
+/// carry IRQs that don't exist on every die, and some headers use naming
+/// that must be fixed up before `fill_peripheral_interrupts` can match an
+/// IRQ to its peripheral.
+///
+/// This module only provides the mechanism. Override files are looked up by
+/// exact chip name, so a file applies to that one die only, not to the other
+/// parts that share its header. `allow`/`deny` entries for a given die have
+/// to be verified against that die's actual reference manual/SVD before
+/// they're trustworthy, so landing this doesn't by itself make every
+/// representative-header fallback correct — only the dies with a
+/// checked-in, audited override file are. As of this writing that's none of
+/// the dies in the `HPM53`/`HPM62`/`HPM67`(+`HPM64`) families: the files of
+/// the representative dies (`HPM5361`, `HPM6280`, `HPM6750`) are checked in
+/// empty as placeholders and the other parts have none, so non-representative
+/// parts in those families still report whatever IRQs the representative
+/// header happens to define. Populating them is tracked per family, not
+/// bundled into unrelated naming fixups like the `DAC` rename in
+/// `HPM6360.yaml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InterruptOverrides {
+    /// Prefixes shared by the representative header that should be trimmed
+    /// for this die before renaming/filtering, e.g. a shared `USB_LP_` prefix.
+    #[serde(default)]
+    strip_prefix: Vec<String>,
+    /// Renames applied after prefix stripping.
+    #[serde(default)]
+    rename: HashMap<String, String>,
+    /// If non-empty, only these interrupt names (after rename) are kept.
+    #[serde(default)]
+    allow: Vec<String>,
+    /// Interrupt names dropped even if present in the header.
+    #[serde(default)]
+    deny: Vec<String>,
+}
+
+fn overrides_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("interrupt_overrides")
+}
+
+/// Load the override file for a chip, if one is checked in. Chips without an
+/// override file get an empty (no-op) `InterruptOverrides`.
+pub fn load_interrupt_overrides(chip_name: &str) -> anyhow::Result<InterruptOverrides> {
+    let path = overrides_dir().join(format!("{}.yaml", chip_name));
+
+    if !path.exists() {
+        return Ok(InterruptOverrides::default());
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| anyhow::anyhow!("Failed to read interrupt overrides {:?}: {}", path, e))?;
+
+    serde_yaml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("Failed to parse interrupt overrides {:?}: {}", path, e))
+}
+
+/// Apply a chip's overrides to the raw header-parsed interrupt set: strip
+/// shared prefixes, rename, then intersect against the allow/deny lists so
+/// non-representative dies only keep the IRQs that actually exist on them.
+pub fn apply_interrupt_overrides(
+    interrupts: HashMap<String, u8>,
+    overrides: &InterruptOverrides,
+) -> HashMap<String, u8> {
+    let mut result = HashMap::with_capacity(interrupts.len());
+
+    for (mut name, number) in interrupts {
+        for prefix in &overrides.strip_prefix {
+            if let Some(stripped) = name.strip_prefix(prefix.as_str()) {
+                name = stripped.to_string();
+            }
+        }
+
+        if let Some(renamed) = overrides.rename.get(&name) {
+            name = renamed.clone();
+        }
+
+        if overrides.deny.contains(&name) {
+            continue;
+        }
+
+        if !overrides.allow.is_empty() && !overrides.allow.contains(&name) {
+            continue;
+        }
+
+        result.insert(name, number);
+    }
+
+    result
+}
@@ -1,3 +1,4 @@
+use crate::interrupt_overrides::{apply_interrupt_overrides, load_interrupt_overrides};
 use regex::Regex;
 use std::{
     collections::HashMap,
@@ -25,9 +26,7 @@ fn parse_interrupts_from_header(header_path: &Path) -> anyhow::Result<HashMap<St
             .parse::<u8>()
             .expect("Failed to parse interrupt number");
 
-        // Apply naming fixes for consistency
-        let fixed_name = fix_interrupt_naming(&irq_name);
-        interrupts.insert(fixed_name, irq_number);
+        interrupts.insert(irq_name, irq_number);
     }
 
     if interrupts.is_empty() {
@@ -43,21 +42,6 @@ fn parse_interrupts_from_header(header_path: &Path) -> anyhow::Result<HashMap<St
     Ok(interrupts)
 }
 
-/// Fix naming inconsistencies between header and expected naming  
-fn fix_interrupt_naming(name: &str) -> String {
-    // Mapping table for naming fixes based on verification results
-    // Note: We prioritize header file naming as it's the official source
-    let name_fixes: HashMap<&str, &str> = [
-        ("DAC", "DAC0"), // HPM6360: header DAC -> expected DAC0 consistency
-                         // For HPM5301: header has PEWDG, TRGMUX0, TRGMUX1 (these are correct)
-                         // For other inconsistencies, we keep header names as authoritative
-    ]
-    .into_iter()
-    .collect();
-
-    name_fixes.get(name).unwrap_or(&name).to_string()
-}
-
 /// Get interrupts header path for chip
 fn get_interrupts_header_path(chip_name: &str) -> Option<PathBuf> {
     let sdk_path = std::env::var("HPM_SDK_BASE")
@@ -86,11 +70,13 @@ fn get_interrupts_header_path(chip_name: &str) -> Option<PathBuf> {
     }
 }
 
-/// Load interrupts from header file for the given chip
+/// Load interrupts from header file for the given chip, with this chip's
+/// naming/availability overrides (see `interrupt_overrides.rs`) applied.
 pub fn load_interrupts_from_header(chip_name: &str) -> anyhow::Result<Option<HashMap<String, u8>>> {
     if let Some(header_path) = get_interrupts_header_path(chip_name) {
         let interrupts = parse_interrupts_from_header(&header_path)?;
-        Ok(Some(interrupts))
+        let overrides = load_interrupt_overrides(chip_name)?;
+        Ok(Some(apply_interrupt_overrides(interrupts, &overrides)))
     } else {
         Ok(None)
     }
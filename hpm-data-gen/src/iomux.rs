@@ -0,0 +1,142 @@
+use regex::Regex;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+/// One `(peripheral, signal, alt_function)` entry parsed off a single pad.
+struct PadFunction {
+    peripheral: String,
+    signal: String,
+    alt_function: u8,
+}
+
+/// Parse pin muxing from header file (hpm_iomux.h)
+fn parse_iomux_from_header(header_path: &Path) -> anyhow::Result<HashMap<String, Vec<PadFunction>>> {
+    let content = std::fs::read_to_string(header_path)
+        .map_err(|e| anyhow::anyhow!("Failed to read iomux header {:?}: {}", header_path, e))?;
+
+    // Match: #define IOC_PAD_PA00_FUNC_CTL_ALT_SELECT_UART0_TXD (1)
+    let pattern = Regex::new(
+        r"#define\s+IOC_PAD_(P[A-Z]\d+)_FUNC_CTL_ALT_SELECT_(\w+?)_(\w+)\s+\((\d+)\)",
+    )
+    .expect("Invalid iomux regex");
+
+    let mut pads: HashMap<String, Vec<PadFunction>> = HashMap::new();
+
+    for cap in pattern.captures_iter(&content) {
+        let pad = cap.get(1).unwrap().as_str().to_string();
+        let peripheral = cap.get(2).unwrap().as_str().to_string();
+        let signal = cap.get(3).unwrap().as_str().to_string();
+        let alt_function = cap
+            .get(4)
+            .unwrap()
+            .as_str()
+            .parse::<u8>()
+            .expect("Failed to parse alt function number");
+
+        pads.entry(pad).or_default().push(PadFunction {
+            peripheral,
+            signal,
+            alt_function,
+        });
+    }
+
+    if pads.is_empty() {
+        anyhow::bail!("No IOMUX definitions found in {:?}", header_path);
+    }
+
+    println!(
+        "    Loaded {} pads from header: {:?}",
+        pads.len(),
+        header_path.file_name().unwrap()
+    );
+
+    Ok(pads)
+}
+
+/// Get iomux header path for chip
+fn get_iomux_header_path(chip_name: &str) -> Option<PathBuf> {
+    let sdk_path = std::env::var("HPM_SDK_BASE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| std::env::current_dir().unwrap().join("./hpm_sdk"));
+
+    let header_path = match chip_name {
+        n if n.starts_with("HPM5301") => sdk_path.join("soc/HPM5300/HPM5301/hpm_iomux.h"),
+        n if n.starts_with("HPM53") => sdk_path.join("soc/HPM5300/HPM5361/hpm_iomux.h"),
+        n if n.starts_with("HPM5E") => sdk_path.join("soc/HPM5E00/HPM5E31/hpm_iomux.h"),
+        n if n.starts_with("HPM62") => sdk_path.join("soc/HPM6200/HPM6280/hpm_iomux.h"),
+        n if n.starts_with("HPM63") => sdk_path.join("soc/HPM6300/HPM6360/hpm_iomux.h"),
+        n if n.starts_with("HPM67") || n.starts_with("HPM64") => {
+            sdk_path.join("soc/HPM6700/HPM6750/hpm_iomux.h")
+        }
+        n if n.starts_with("HPM68") => sdk_path.join("soc/HPM6800/HPM6880/hpm_iomux.h"),
+        n if n.starts_with("HPM6E") => sdk_path.join("soc/HPM6E00/HPM6E80/hpm_iomux.h"),
+        n if n.starts_with("HPM6P") => sdk_path.join("soc/HPM6P00/HPM6P81/hpm_iomux.h"),
+        _ => return None,
+    };
+
+    if header_path.exists() {
+        Some(header_path)
+    } else {
+        None
+    }
+}
+
+/// Load pin muxing from header file for the given chip
+pub fn load_iomux_from_header(
+    chip_name: &str,
+) -> anyhow::Result<Option<HashMap<String, Vec<PadFunction>>>> {
+    if let Some(header_path) = get_iomux_header_path(chip_name) {
+        let pads = parse_iomux_from_header(&header_path)?;
+        Ok(Some(pads))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Populates each peripheral's `pins`, which `codegen::write_metadata` in
+/// turn collects into `Metadata.pins`/`foreach_pin!` so a HAL build script
+/// can type-check pin-to-peripheral assignments.
+pub fn fill_peripheral_pins(chip: &mut hpm_data_serde::Chip) -> anyhow::Result<()> {
+    let pads = match load_iomux_from_header(&chip.name)? {
+        Some(pads) => pads,
+        None => return Ok(()),
+    };
+
+    // Pads come out of a HashMap, so walk them in a stable order (pad, then
+    // peripheral/signal/af) rather than hash-iteration order, which would
+    // otherwise make `periph.pins` — and anything hashing it, like
+    // `dedup::hash_core` — nondeterministic run-to-run.
+    let mut pads: Vec<_> = pads.into_iter().collect();
+    pads.sort_by(|(pad_a, _), (pad_b, _)| pad_a.cmp(pad_b));
+    for (_, functions) in &mut pads {
+        functions.sort_by(|a, b| {
+            (&a.peripheral, &a.signal, a.alt_function).cmp(&(&b.peripheral, &b.signal, b.alt_function))
+        });
+    }
+
+    for core in chip.cores.iter_mut() {
+        for (pad, functions) in &pads {
+            for function in functions {
+                for periph in core.peripherals.iter_mut() {
+                    if periph.name != function.peripheral {
+                        continue;
+                    }
+
+                    let mut pins = periph.pins.take().unwrap_or_default();
+
+                    pins.push(hpm_data_serde::chip::core::peripheral::Pin {
+                        pin: pad.clone(),
+                        signal: function.signal.clone(),
+                        af: function.alt_function,
+                    });
+
+                    periph.pins = Some(pins);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use regex::Regex;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+/// Parse memory regions from header file (hpm_memory_map.h)
+fn parse_memory_regions_from_header(
+    header_path: &Path,
+) -> anyhow::Result<HashMap<String, (usize, usize)>> {
+    let content = std::fs::read_to_string(header_path).map_err(|e| {
+        anyhow::anyhow!("Failed to read memory map header {:?}: {}", header_path, e)
+    })?;
+
+    // Match: #define ILM_BASE (0x0UL) and #define ILM_SIZE (0x40000UL)
+    let base_pattern =
+        Regex::new(r"#define\s+(\w+)_BASE\s+\((0x[0-9A-Fa-f]+)UL\)").expect("Invalid base regex");
+    let size_pattern =
+        Regex::new(r"#define\s+(\w+)_SIZE\s+\((0x[0-9A-Fa-f]+)UL\)").expect("Invalid size regex");
+
+    let mut bases = HashMap::new();
+    for cap in base_pattern.captures_iter(&content) {
+        let name = cap.get(1).unwrap().as_str().to_string();
+        let base = usize::from_str_radix(&cap.get(2).unwrap().as_str()[2..], 16)
+            .expect("Failed to parse base address");
+        bases.insert(name, base);
+    }
+
+    let mut regions = HashMap::new();
+    for cap in size_pattern.captures_iter(&content) {
+        let name = cap.get(1).unwrap().as_str().to_string();
+        let size = usize::from_str_radix(&cap.get(2).unwrap().as_str()[2..], 16)
+            .expect("Failed to parse size");
+
+        if let Some(base) = bases.get(&name) {
+            regions.insert(name, (*base, size));
+        }
+    }
+
+    if regions.is_empty() {
+        anyhow::bail!("No memory region definitions found in {:?}", header_path);
+    }
+
+    println!(
+        "    Loaded {} memory regions from header: {:?}",
+        regions.len(),
+        header_path.file_name().unwrap()
+    );
+
+    Ok(regions)
+}
+
+/// Get memory map header path for chip
+fn get_memory_header_path(chip_name: &str) -> Option<PathBuf> {
+    let sdk_path = std::env::var("HPM_SDK_BASE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| std::env::current_dir().unwrap().join("./hpm_sdk"));
+
+    let header_path = match chip_name {
+        n if n.starts_with("HPM5301") => sdk_path.join("soc/HPM5300/HPM5301/hpm_memory_map.h"),
+        n if n.starts_with("HPM53") => sdk_path.join("soc/HPM5300/HPM5361/hpm_memory_map.h"),
+        n if n.starts_with("HPM5E") => sdk_path.join("soc/HPM5E00/HPM5E31/hpm_memory_map.h"),
+        n if n.starts_with("HPM62") => sdk_path.join("soc/HPM6200/HPM6280/hpm_memory_map.h"),
+        n if n.starts_with("HPM63") => sdk_path.join("soc/HPM6300/HPM6360/hpm_memory_map.h"),
+        n if n.starts_with("HPM67") || n.starts_with("HPM64") => {
+            sdk_path.join("soc/HPM6700/HPM6750/hpm_memory_map.h")
+        }
+        n if n.starts_with("HPM68") => sdk_path.join("soc/HPM6800/HPM6880/hpm_memory_map.h"),
+        n if n.starts_with("HPM6E") => sdk_path.join("soc/HPM6E00/HPM6E80/hpm_memory_map.h"),
+        n if n.starts_with("HPM6P") => sdk_path.join("soc/HPM6P00/HPM6P81/hpm_memory_map.h"),
+        _ => return None,
+    };
+
+    if header_path.exists() {
+        Some(header_path)
+    } else {
+        None
+    }
+}
+
+/// Load memory regions from header file for the given chip
+pub fn load_memory_regions_from_header(
+    chip_name: &str,
+) -> anyhow::Result<Option<HashMap<String, (usize, usize)>>> {
+    if let Some(header_path) = get_memory_header_path(chip_name) {
+        let regions = parse_memory_regions_from_header(&header_path)?;
+        Ok(Some(regions))
+    } else {
+        Ok(None)
+    }
+}
+
+pub fn fill_chip_memory(chip: &mut hpm_data_serde::Chip) -> anyhow::Result<()> {
+    let regions = match load_memory_regions_from_header(&chip.name)? {
+        Some(regions) => regions,
+        None => return Ok(()),
+    };
+
+    let total_bytes = regions.values().map(|(_, size)| size).sum();
+
+    let regions = regions
+        .into_iter()
+        .map(|(name, (base, bytes))| {
+            (
+                name,
+                hpm_data_serde::chip::Region {
+                    base: base as u32,
+                    bytes: bytes as u32,
+                },
+            )
+        })
+        .collect();
+
+    chip.memory = Some(hpm_data_serde::chip::Memory {
+        bytes: total_bytes,
+        regions,
+    });
+
+    Ok(())
+}